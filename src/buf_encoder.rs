@@ -0,0 +1,188 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! A fixed-size buffer that hex bytes are encoded into before being handed to a [`fmt::Formatter`]
+//! or sink.
+//!
+//! The buffer backing [`BufEncoder`] starts out uninitialized (see [`OutBytes::uninit_array`]) and
+//! [`BufEncoder`] itself tracks how much of it has actually been written, so callers never pay for
+//! zeroing memory they're about to overwrite and never read bytes that were never written.
+//!
+//! [`fmt::Formatter`]: core::fmt::Formatter
+
+use core::borrow::Borrow;
+use core::mem::MaybeUninit;
+
+use super::Case;
+
+/// A byte buffer that may be partially or fully uninitialized.
+///
+/// This is `#[repr(transparent)]` over `[MaybeUninit<u8>]` so a `&mut [MaybeUninit<u8>; N]` can be
+/// reinterpreted as `&mut OutBytes` for free via [`AsOutBytes`]. [`BufEncoder`] is the only way to
+/// write into it and is the one that knows how many leading bytes are actually initialized.
+#[repr(transparent)]
+pub struct OutBytes {
+    buf: [MaybeUninit<u8>],
+}
+
+impl OutBytes {
+    /// Returns an uninitialized, stack-allocated array of `N` bytes.
+    ///
+    /// `MaybeUninit<u8>` is `Copy`, so this is a plain array literal - no zeroing, no looping.
+    #[inline]
+    pub fn uninit_array<const N: usize>() -> [MaybeUninit<u8>; N] { [MaybeUninit::uninit(); N] }
+
+    #[inline]
+    fn len(&self) -> usize { self.buf.len() }
+
+    #[inline]
+    fn as_mut_ptr(&mut self) -> *mut u8 { self.buf.as_mut_ptr().cast::<u8>() }
+
+    /// # Safety
+    ///
+    /// The caller must guarantee that the first `len` bytes of `self` are initialized and hold
+    /// valid ASCII.
+    #[inline]
+    unsafe fn as_str_unchecked(&self, len: usize) -> &str {
+        debug_assert!(len <= self.len());
+        // SAFETY: caller guarantees the first `len` bytes are initialized; `len <= self.len()` so
+        // the slice is in bounds.
+        let init = unsafe { core::slice::from_raw_parts(self.buf.as_ptr().cast::<u8>(), len) };
+        // SAFETY: caller guarantees `init` holds valid ASCII, which is valid UTF-8.
+        unsafe { core::str::from_utf8_unchecked(init) }
+    }
+}
+
+/// Converts `self` into an (uninitialized) [`OutBytes`] buffer.
+///
+/// Implemented for fixed-size `MaybeUninit<u8>` arrays so callers can pass a plain stack buffer to
+/// [`BufEncoder`] without naming `OutBytes` explicitly.
+pub trait AsOutBytes {
+    /// Returns a mutable view of `self` as an [`OutBytes`] buffer.
+    fn as_mut_out_bytes(&mut self) -> &mut OutBytes;
+}
+
+impl<const N: usize> AsOutBytes for [MaybeUninit<u8>; N] {
+    #[inline]
+    fn as_mut_out_bytes(&mut self) -> &mut OutBytes {
+        // SAFETY: `OutBytes` is `#[repr(transparent)]` over `[MaybeUninit<u8>]`.
+        unsafe { &mut *(self.as_mut_slice() as *mut [MaybeUninit<u8>] as *mut OutBytes) }
+    }
+}
+
+impl AsOutBytes for OutBytes {
+    #[inline]
+    fn as_mut_out_bytes(&mut self) -> &mut OutBytes { self }
+}
+
+/// A fixed-length, stack-allocated backing buffer for [`DisplayArray`](crate::display::DisplayArray).
+///
+/// # Safety
+///
+/// Implementors must return a buffer from [`uninit`](Self::uninit) whose length (in bytes) never
+/// changes between calls - `DisplayArray` relies on it matching the encoded length of its payload.
+pub unsafe trait FixedLenBuf: AsOutBytes {
+    /// Returns a buffer of the implementor's fixed length, contents unspecified.
+    fn uninit() -> Self;
+}
+
+macro_rules! impl_fixed_len_buf {
+    ($($len_in_hex_chars:expr),* $(,)?) => {
+        $(
+            // SAFETY: the array always has length `$len_in_hex_chars`.
+            unsafe impl FixedLenBuf for [MaybeUninit<u8>; $len_in_hex_chars] {
+                #[inline]
+                fn uninit() -> Self { OutBytes::uninit_array::<$len_in_hex_chars>() }
+            }
+        )*
+    };
+}
+
+// Hex-char lengths (2x the byte length) for common fixed-size hash/key types.
+impl_fixed_len_buf!(8, 16, 32, 40, 56, 64, 66, 96, 128, 130, 192, 256, 512, 1024);
+
+/// Encodes bytes as hex chars into a fixed-size [`OutBytes`] buffer.
+///
+/// Tracks how many bytes of the buffer it has written so [`as_str`](Self::as_str) never exposes
+/// uninitialized or stale memory, which is what lets the buffer start out uninitialized instead of
+/// zeroed.
+pub struct BufEncoder<'a> {
+    buf: &'a mut OutBytes,
+    len: usize,
+}
+
+impl<'a> BufEncoder<'a> {
+    /// Creates an encoder writing into `buf`, which may already hold initialized bytes - they're
+    /// simply ignored and will be overwritten.
+    #[inline]
+    pub fn new(buf: &'a mut (impl AsOutBytes + ?Sized)) -> Self {
+        BufEncoder { buf: buf.as_mut_out_bytes(), len: 0 }
+    }
+
+    /// Creates an encoder writing into `buf`, which is assumed to start out uninitialized.
+    ///
+    /// Behaves identically to [`new`](Self::new) - the encoder never reads `buf` before writing it
+    /// - this constructor just documents, at the call site, that there's no need to initialize
+    /// `buf` first.
+    #[inline]
+    pub fn from_uninit(buf: &'a mut (impl AsOutBytes + ?Sized)) -> Self { Self::new(buf) }
+
+    /// Resets the encoder to empty without touching the backing buffer.
+    #[inline]
+    pub fn clear(&mut self) { self.len = 0; }
+
+    /// Returns the hex chars written so far.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        // SAFETY: `self.len` only ever counts bytes this encoder has itself written as hex ASCII.
+        unsafe { self.buf.as_str_unchecked(self.len) }
+    }
+
+    /// Encodes `bytes` as hex chars and appends them to the buffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bytes` doesn't fit in the remaining buffer capacity.
+    pub fn put_bytes<I>(&mut self, bytes: I, case: Case)
+    where
+        I: IntoIterator,
+        I::Item: Borrow<u8>,
+    {
+        let table = case.table();
+        let cap = self.buf.len();
+        for byte in bytes {
+            let [hi, lo] = super::byte_to_hex(*byte.borrow(), table);
+            assert!(self.len + 2 <= cap, "BufEncoder buffer is full");
+            // SAFETY: just checked `self.len + 2 <= cap`, so both offsets are in bounds; they may
+            // hold bytes from before a `clear`, but `u8` needs no dropping so overwriting is fine.
+            unsafe {
+                self.buf.as_mut_ptr().add(self.len).write(hi);
+                self.buf.as_mut_ptr().add(self.len + 1).write(lo);
+            }
+            self.len += 2;
+        }
+    }
+
+    /// Fills the buffer with as many repetitions of `c` as fit (capped at `len` repetitions),
+    /// returning the number of repetitions actually written.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len > 0` and not even one repetition of `c` fits in the remaining capacity.
+    pub fn put_filler(&mut self, c: char, len: usize) -> usize {
+        let mut encoded = [0u8; 4];
+        let char_len = c.encode_utf8(&mut encoded).len();
+        let cap = self.buf.len();
+        let max_repeats = core::cmp::max(1, (cap - self.len) / char_len);
+        let repeats = core::cmp::min(max_repeats, len);
+        assert!(self.len + repeats * char_len <= cap, "BufEncoder buffer is full");
+        for _ in 0..repeats {
+            for &b in &encoded[..char_len] {
+                // SAFETY: asserted above that all `repeats * char_len` bytes written here, starting
+                // at the initial `self.len`, end at or before `cap`.
+                unsafe { self.buf.as_mut_ptr().add(self.len).write(b) };
+                self.len += 1;
+            }
+        }
+        repeats
+    }
+}
@@ -27,6 +27,7 @@
 
 use core::borrow::Borrow;
 use core::fmt;
+use core::mem::MaybeUninit;
 
 use super::Case;
 use crate::buf_encoder::{BufEncoder, FixedLenBuf, OutBytes};
@@ -144,6 +145,98 @@ impl<'a> DisplayHex for &'a alloc::vec::Vec<u8> {
     }
 }
 
+/// Extension trait for types that can be displayed as hex with their bytes in reverse order.
+///
+/// This is useful for Bitcoin-style hashes (txids, block hashes) which are conventionally
+/// displayed with their bytes reversed. Per-byte nibble order is unaffected - only the order in
+/// which bytes are visited changes.
+pub trait DisplayHexReversed: Copy + sealed::IsRef {
+    /// The type providing [`fmt::Display`] implementation.
+    type Display: fmt::Display + fmt::Debug + fmt::LowerHex + fmt::UpperHex;
+
+    /// Display `Self` as a continuous sequence of ASCII hex chars, with bytes in reverse order.
+    fn as_hex_reversed(self) -> Self::Display;
+}
+
+impl<'a> DisplayHexReversed for &'a [u8] {
+    type Display = DisplayByteSliceReversed<'a>;
+
+    #[inline]
+    fn as_hex_reversed(self) -> Self::Display { DisplayByteSliceReversed { bytes: self } }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> DisplayHexReversed for &'a alloc::vec::Vec<u8> {
+    type Display = DisplayByteSliceReversed<'a>;
+
+    #[inline]
+    fn as_hex_reversed(self) -> Self::Display { DisplayByteSliceReversed { bytes: self } }
+}
+
+// Shared by the `Display` wrappers below. Given the number of hex chars the (untruncated)
+// encoding would produce, works out how much of any requested `width` precision would leave
+// unfilled, writes the left-hand share of that through `encoder` (clearing it again afterwards),
+// and returns how much is still owed on the right once the content itself has been written.
+fn pad_left_and_remaining_right(
+    f: &mut fmt::Formatter,
+    encoder: &mut BufEncoder,
+    encoded_len: usize,
+) -> Result<usize, fmt::Error> {
+    use fmt::Write;
+
+    let width = match f.width() {
+        Some(width) => width,
+        None => return Ok(0),
+    };
+    let string_len = match f.precision() {
+        Some(max) if encoded_len > max => max,
+        _ => encoded_len,
+    };
+    if string_len >= width {
+        return Ok(0);
+    }
+    let (left, right) = match f.align().unwrap_or(fmt::Alignment::Left) {
+        fmt::Alignment::Left => (0, width - string_len),
+        fmt::Alignment::Right => (width - string_len, 0),
+        fmt::Alignment::Center => ((width - string_len) / 2, (width - string_len + 1) / 2),
+    };
+    // Avoid division by zero and optimize for common case.
+    if left > 0 {
+        let c = f.fill();
+        let chunk_len = encoder.put_filler(c, left);
+        let padding = encoder.as_str();
+        for _ in 0..(left / chunk_len) {
+            f.write_str(padding)?;
+        }
+        f.write_str(&padding[..((left % chunk_len) * c.len_utf8())])?;
+        encoder.clear();
+    }
+    Ok(right)
+}
+
+// Writes `pad` more repetitions of the formatter's fill char through `encoder`. Pairs with
+// `pad_left_and_remaining_right`, which computes `pad`.
+fn write_right_padding(
+    f: &mut fmt::Formatter,
+    encoder: &mut BufEncoder,
+    pad: usize,
+) -> fmt::Result {
+    use fmt::Write;
+
+    // Avoid division by zero and optimize for common case.
+    if pad == 0 {
+        return Ok(());
+    }
+    encoder.clear();
+    let c = f.fill();
+    let chunk_len = encoder.put_filler(c, pad);
+    let padding = encoder.as_str();
+    for _ in 0..(pad / chunk_len) {
+        f.write_str(padding)?;
+    }
+    f.write_str(&padding[..((pad % chunk_len) * c.len_utf8())])
+}
+
 /// Displays byte slice as hex.
 ///
 /// Created by [`<&[u8] as DisplayHex>::as_hex`](DisplayHex::as_hex).
@@ -155,55 +248,29 @@ pub struct DisplayByteSlice<'a> {
 impl<'a> DisplayByteSlice<'a> {
     fn display(&self, f: &mut fmt::Formatter, case: Case) -> fmt::Result {
         use fmt::Write;
-        // There are at least two optimizations left:
-        //
-        // * Reusing the buffer (encoder) which may decrease the number of virtual calls
-        // * Not recursing, avoiding another 1024B allocation and zeroing
-        //
-        // This would complicate the code so I was too lazy to do them but feel free to send a PR!
-
-        let mut buf = [0u8; 1024];
-        let mut encoder = BufEncoder::new(&mut buf);
-
-        let pad_right = if let Some(width) = f.width() {
-            let string_len = match f.precision() {
-                Some(max) if self.bytes.len() * 2 > (max + 1) / 2 => max,
-                Some(_) | None => self.bytes.len() * 2,
-            };
-
-            if string_len < width {
-                let (left, right) = match f.align().unwrap_or(fmt::Alignment::Left) {
-                    fmt::Alignment::Left => (0, width - string_len),
-                    fmt::Alignment::Right => (width - string_len, 0),
-                    fmt::Alignment::Center =>
-                        ((width - string_len) / 2, (width - string_len + 1) / 2),
-                };
-                // Avoid division by zero and optimize for common case.
-                if left > 0 {
-                    let c = f.fill();
-                    let chunk_len = encoder.put_filler(c, left);
-                    let padding = encoder.as_str();
-                    for _ in 0..(left / chunk_len) {
-                        f.write_str(padding)?;
-                    }
-                    f.write_str(&padding[..((left % chunk_len) * c.len_utf8())])?;
-                    encoder.clear();
-                }
-                right
-            } else {
-                0
-            }
-        } else {
-            0
-        };
+
+        let mut buf = OutBytes::uninit_array::<1024>();
+        let mut encoder = BufEncoder::from_uninit(&mut buf);
+
+        let pad_right = pad_left_and_remaining_right(f, &mut encoder, self.bytes.len() * 2)?;
 
         match f.precision() {
-            Some(max) if self.bytes.len() > (max + 1) / 2 => {
-                write!(f, "{}", self.bytes[..(max / 2)].as_hex())?;
-                if max % 2 == 1 && self.bytes.len() > max / 2 + 1 {
-                    f.write_char(
-                        super::byte_to_hex(self.bytes[max / 2 + 1], case.table())[1].into(),
-                    )?;
+            Some(max) if self.bytes.len() * 2 > max => {
+                // `n` full bytes give us `2 * n <= max` hex chars; if `max` is odd we need one
+                // more nibble from the following byte, which `self.bytes[n]` is guaranteed to be
+                // in bounds for since `self.bytes.len() * 2 > max` implies `self.bytes.len() > n`.
+                let n = max / 2;
+                let mut chunks = self.bytes[..n].chunks_exact(512);
+                for chunk in &mut chunks {
+                    encoder.put_bytes(chunk, case);
+                    f.write_str(encoder.as_str())?;
+                    encoder.clear();
+                }
+                encoder.put_bytes(chunks.remainder(), case);
+                f.write_str(encoder.as_str())?;
+                if max % 2 == 1 {
+                    encoder.clear();
+                    f.write_char(super::byte_to_hex(self.bytes[n], case.table())[0].into())?;
                 }
             }
             Some(_) | None => {
@@ -218,17 +285,7 @@ impl<'a> DisplayByteSlice<'a> {
             }
         }
 
-        // Avoid division by zero and optimize for common case.
-        if pad_right > 0 {
-            encoder.clear();
-            let c = f.fill();
-            let chunk_len = encoder.put_filler(c, pad_right);
-            let padding = encoder.as_str();
-            for _ in 0..(pad_right / chunk_len) {
-                f.write_str(padding)?;
-            }
-            f.write_str(&padding[..((pad_right % chunk_len) * c.len_utf8())])?;
-        }
+        write_right_padding(f, &mut encoder, pad_right)?;
         Ok(())
     }
 }
@@ -249,6 +306,150 @@ impl<'a> fmt::UpperHex for DisplayByteSlice<'a> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { self.display(f, Case::Upper) }
 }
 
+/// Displays byte slice as hex with bytes in reverse order.
+///
+/// Created by [`<&[u8] as DisplayHexReversed>::as_hex_reversed`](DisplayHexReversed::as_hex_reversed).
+pub struct DisplayByteSliceReversed<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> DisplayByteSliceReversed<'a> {
+    fn display(&self, f: &mut fmt::Formatter, case: Case) -> fmt::Result {
+        use fmt::Write;
+
+        let mut buf = OutBytes::uninit_array::<1024>();
+        let mut encoder = BufEncoder::from_uninit(&mut buf);
+
+        let pad_right = pad_left_and_remaining_right(f, &mut encoder, self.bytes.len() * 2)?;
+
+        // Precision selects the most significant bytes which, because we're displaying in
+        // reverse, are the ones at the *end* of the in-memory slice and are emitted *first*.
+        match f.precision() {
+            Some(max) if self.bytes.len() * 2 > max => {
+                let n = max / 2;
+                let (rest, selected) = self.bytes.split_at(self.bytes.len() - n);
+                let mut chunks = selected.rchunks(512);
+                for chunk in &mut chunks {
+                    encoder.put_bytes(chunk.iter().rev(), case);
+                    f.write_str(encoder.as_str())?;
+                    encoder.clear();
+                }
+                if max % 2 == 1 {
+                    if let Some(&byte) = rest.last() {
+                        f.write_char(super::byte_to_hex(byte, case.table())[0].into())?;
+                    }
+                }
+            }
+            Some(_) | None => {
+                let mut chunks = self.bytes.rchunks(512);
+                for chunk in &mut chunks {
+                    encoder.put_bytes(chunk.iter().rev(), case);
+                    f.write_str(encoder.as_str())?;
+                    encoder.clear();
+                }
+            }
+        }
+
+        write_right_padding(f, &mut encoder, pad_right)?;
+        Ok(())
+    }
+}
+
+impl<'a> fmt::Display for DisplayByteSliceReversed<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { fmt::LowerHex::fmt(self, f) }
+}
+
+impl<'a> fmt::Debug for DisplayByteSliceReversed<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { fmt::LowerHex::fmt(self, f) }
+}
+
+impl<'a> fmt::LowerHex for DisplayByteSliceReversed<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { self.display(f, Case::Lower) }
+}
+
+impl<'a> fmt::UpperHex for DisplayByteSliceReversed<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { self.display(f, Case::Upper) }
+}
+
+/// Extension trait for displaying an arbitrary, cheaply cloneable byte iterator as hex, lazily.
+///
+/// Unlike [`DisplayHex`], this doesn't require the bytes to live in a slice or array, so it also
+/// works for synthetic or filtered byte streams, e.g. `some_iter.map(|x| x.0).as_hex()`.
+pub trait IterDisplayHex: Iterator<Item = u8> + Clone + Sized {
+    /// Displays `self` as a continuous sequence of ASCII hex chars, encoding lazily.
+    fn as_hex(self) -> DisplayByteIter<Self> { DisplayByteIter { iter: self } }
+}
+
+impl<I: Iterator<Item = u8> + Clone> IterDisplayHex for I {}
+
+/// Displays an arbitrary cloneable byte iterator as hex, encoding lazily through a chunked buffer.
+///
+/// Created by [`IterDisplayHex::as_hex`].
+pub struct DisplayByteIter<I> {
+    iter: I,
+}
+
+impl<I: Iterator<Item = u8> + Clone> DisplayByteIter<I> {
+    fn display(&self, f: &mut fmt::Formatter, case: Case) -> fmt::Result {
+        use fmt::Write;
+
+        let mut buf = OutBytes::uninit_array::<1024>();
+        let mut encoder = BufEncoder::from_uninit(&mut buf);
+
+        // There's no cheap way to know the length up front, so width-based padding re-clones and
+        // walks the iterator once to compute it.
+        let pad_right = if f.width().is_some() {
+            pad_left_and_remaining_right(f, &mut encoder, self.iter.clone().count() * 2)?
+        } else {
+            0
+        };
+
+        // Feed `iter` through `encoder` in chunks of at most 512 bytes (the encoder's 1024-byte
+        // buffer holds exactly that many bytes of hex), stopping early once `limit` bytes have
+        // been consumed or `iter` runs dry, whichever comes first.
+        let mut iter = self.iter.clone();
+        let limit = f.precision().map(|max| max / 2).unwrap_or(usize::MAX);
+        let mut taken = 0;
+        while taken < limit {
+            let this_chunk = core::cmp::min(limit - taken, 512);
+            let mut produced = 0;
+            encoder.put_bytes((&mut iter).take(this_chunk).inspect(|_| produced += 1), case);
+            f.write_str(encoder.as_str())?;
+            encoder.clear();
+            taken += produced;
+            if produced < this_chunk {
+                break; // `iter` is exhausted.
+            }
+        }
+        if let Some(max) = f.precision() {
+            if max % 2 == 1 && taken == limit {
+                if let Some(byte) = iter.next() {
+                    f.write_char(super::byte_to_hex(byte, case.table())[0].into())?;
+                }
+            }
+        }
+
+        write_right_padding(f, &mut encoder, pad_right)?;
+        Ok(())
+    }
+}
+
+impl<I: Iterator<Item = u8> + Clone> fmt::Display for DisplayByteIter<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { fmt::LowerHex::fmt(self, f) }
+}
+
+impl<I: Iterator<Item = u8> + Clone> fmt::Debug for DisplayByteIter<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { fmt::LowerHex::fmt(self, f) }
+}
+
+impl<I: Iterator<Item = u8> + Clone> fmt::LowerHex for DisplayByteIter<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { self.display(f, Case::Lower) }
+}
+
+impl<I: Iterator<Item = u8> + Clone> fmt::UpperHex for DisplayByteIter<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { self.display(f, Case::Upper) }
+}
+
 /// Displays byte array as hex.
 ///
 /// Created by [`<&[u8; LEN] as DisplayHex>::as_hex`](DisplayHex::as_hex).
@@ -271,7 +472,7 @@ where
 
     fn display(&self, f: &mut fmt::Formatter, case: Case) -> fmt::Result {
         let mut buf = B::uninit();
-        let mut encoder = BufEncoder::new(&mut buf);
+        let mut encoder = BufEncoder::from_uninit(&mut buf);
         encoder.put_bytes(self.array.clone(), case);
         f.pad_integral(true, "0x", encoder.as_str())
     }
@@ -334,7 +535,7 @@ macro_rules! fmt_hex_exact {
         #[allow(deprecated)]
         const _: () = [()][($len > usize::MAX / 2) as usize];
         assert_eq!($bytes.len(), $len);
-        let mut buf = [0u8; $len * 2];
+        let mut buf = [core::mem::MaybeUninit::<u8>::uninit(); $len * 2];
         let buf = $crate::buf_encoder::AsOutBytes::as_mut_out_bytes(&mut buf);
         $crate::display::fmt_hex_exact_fn($formatter, buf, $bytes, $case)
     }};
@@ -359,6 +560,118 @@ where
     f.pad_integral(true, "0x", encoder.as_str())
 }
 
+/// Hex-encodes a byte stream into a [`std::io::Write`] sink with bounded memory.
+///
+/// Bytes passed to [`write`](std::io::Write::write) are pushed through a small, fixed-size
+/// chunked encoder and the resulting hex text is written straight to the inner writer, so hashers
+/// or file readers can be streamed to hex output without ever holding the whole input (or its hex
+/// encoding) in memory at once.
+///
+/// Call [`finish`](Self::finish) to retrieve the inner writer once done; `HexWriter` also flushes
+/// on drop so unwinding doesn't silently lose buffered output.
+#[cfg(feature = "std")]
+pub struct HexWriter<W: std::io::Write> {
+    // `ManuallyDrop` so `finish` can move `writer` out despite `HexWriter`'s own `Drop` impl.
+    writer: core::mem::ManuallyDrop<W>,
+    case: Case,
+    buf: [MaybeUninit<u8>; 1024],
+}
+
+#[cfg(feature = "std")]
+impl<W: std::io::Write> HexWriter<W> {
+    /// Creates a new `HexWriter` wrapping `writer`, encoding bytes using `case`.
+    #[inline]
+    pub fn new(writer: W, case: Case) -> Self {
+        HexWriter {
+            writer: core::mem::ManuallyDrop::new(writer),
+            case,
+            buf: OutBytes::uninit_array::<1024>(),
+        }
+    }
+
+    /// Flushes any pending output and returns the inner writer.
+    pub fn finish(mut self) -> std::io::Result<W> {
+        std::io::Write::flush(&mut self)?;
+        // SAFETY: `self` is forgotten immediately below, so its `Drop` impl - which would
+        // otherwise flush through `writer` again after it's been moved out - never runs.
+        let writer = unsafe { core::mem::ManuallyDrop::take(&mut self.writer) };
+        core::mem::forget(self);
+        Ok(writer)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<W: std::io::Write> std::io::Write for HexWriter<W> {
+    fn write(&mut self, data: &[u8]) -> std::io::Result<usize> {
+        // Each byte encodes to two hex chars, so this many input bytes fill `self.buf` exactly.
+        let max_chunk = self.buf.len() / 2;
+        let mut encoder = BufEncoder::new(&mut self.buf);
+        for chunk in data.chunks(max_chunk) {
+            encoder.put_bytes(chunk, self.case);
+            self.writer.write_all(encoder.as_str().as_bytes())?;
+            encoder.clear();
+        }
+        Ok(data.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> { self.writer.flush() }
+}
+
+#[cfg(feature = "std")]
+impl<W: std::io::Write> Drop for HexWriter<W> {
+    fn drop(&mut self) {
+        let _ = std::io::Write::flush(self);
+        // SAFETY: `self` is not used again after this, so `writer` is dropped exactly once.
+        unsafe { core::mem::ManuallyDrop::drop(&mut self.writer) };
+    }
+}
+
+/// Hex-encodes a byte stream into a [`fmt::Write`] sink with bounded memory.
+///
+/// This is the `core`-only counterpart of [`HexWriter`] for callers without `std`: it streams
+/// bytes through the same chunked encoder but writes the resulting hex text into anything
+/// implementing [`fmt::Write`] (a `String`, a `fmt::Formatter`, ...) instead of `std::io::Write`.
+#[cfg(not(feature = "std"))]
+pub struct HexWriter<W: fmt::Write> {
+    writer: W,
+    case: Case,
+    buf: [MaybeUninit<u8>; 1024],
+}
+
+#[cfg(not(feature = "std"))]
+impl<W: fmt::Write> HexWriter<W> {
+    /// Creates a new `HexWriter` wrapping `writer`, encoding bytes using `case`.
+    #[inline]
+    pub fn new(writer: W, case: Case) -> Self {
+        HexWriter { writer, case, buf: OutBytes::uninit_array::<1024>() }
+    }
+
+    /// Encodes `data` as hex and writes it to the inner sink.
+    pub fn write_bytes(&mut self, data: &[u8]) -> fmt::Result {
+        // Each byte encodes to two hex chars, so this many input bytes fill `self.buf` exactly.
+        let max_chunk = self.buf.len() / 2;
+        let mut encoder = BufEncoder::new(&mut self.buf);
+        for chunk in data.chunks(max_chunk) {
+            encoder.put_bytes(chunk, self.case);
+            self.writer.write_str(encoder.as_str())?;
+            encoder.clear();
+        }
+        Ok(())
+    }
+
+    /// Returns the inner writer.
+    pub fn finish(self) -> W { self.writer }
+}
+
+#[cfg(not(feature = "std"))]
+impl<W: fmt::Write> fmt::Write for HexWriter<W> {
+    /// Hex-encodes the bytes of `s` and writes them to the inner sink.
+    ///
+    /// This is what lets `HexWriter` be handed to anything generic over [`fmt::Write`]; each call
+    /// forwards to [`write_bytes`](Self::write_bytes).
+    fn write_str(&mut self, s: &str) -> fmt::Result { self.write_bytes(s.as_bytes()) }
+}
+
 #[cfg(test)]
 mod tests {
     #[cfg(feature = "alloc")]
@@ -454,6 +767,15 @@ mod tests {
             assert_eq!(format!("{0:10.20}", v.as_hex()), "12345678  ");
         }
 
+        #[test]
+        fn precision_with_padding_pads_right_when_precision_exceeds_width_minus_content() {
+            // Regression test: the padding width must be based on the *actual* encoded length
+            // (8 hex chars), not on `precision` (10), even though precision here is larger than
+            // the encoded length but smaller than `width`.
+            let v = vec![0x12, 0x34, 0x56, 0x78];
+            assert_eq!(format!("{0:12.10}", v.as_hex()), "12345678    ");
+        }
+
         #[test]
         fn precision_with_padding_pads_left() {
             let v = vec![0x12, 0x34, 0x56, 0x78];
@@ -478,6 +800,25 @@ mod tests {
             assert_eq!(format!("{0:.16}", v.as_hex()), "12345678");
         }
 
+        #[test]
+        fn odd_precision_truncates_mid_byte() {
+            // Precision 3 keeps the first full byte ("12") plus the high nibble of the next ("3").
+            let v = vec![0x12, 0x34, 0x56, 0x78];
+            assert_eq!(format!("{0:.3}", v.as_hex()), "123");
+        }
+
+        #[test]
+        fn odd_precision_truncates_mid_byte_with_single_byte_left() {
+            let v = vec![0x12, 0x34];
+            assert_eq!(format!("{0:.1}", v.as_hex()), "1");
+        }
+
+        #[test]
+        fn odd_precision_with_padding_truncates_mid_byte() {
+            let v = vec![0x12, 0x34, 0x56, 0x78];
+            assert_eq!(format!("{0:6.3}", v.as_hex()), "123   ");
+        }
+
         #[test]
         fn padding_extends() {
             let v = vec![0xab; 2];
@@ -489,5 +830,137 @@ mod tests {
             let v = vec![0x12, 0x34, 0x56, 0x78];
             assert_eq!(format!("{:0>4}", v.as_hex()), "12345678");
         }
+
+        #[test]
+        fn reversed_matches_manually_reversed_bytes() {
+            let v = vec![0x12, 0x34, 0x56, 0x78];
+            let mut reversed = v.clone();
+            reversed.reverse();
+            assert_eq!(format!("{}", v.as_hex_reversed()), format!("{}", reversed.as_hex()));
+        }
+
+        #[test]
+        fn reversed_long() {
+            let v: Vec<u8> = (0u8..=255).cycle().take(600).collect();
+            let mut reversed = v.clone();
+            reversed.reverse();
+            assert_eq!(format!("{}", v.as_hex_reversed()), format!("{}", reversed.as_hex()));
+        }
+
+        #[test]
+        fn reversed_precision_truncates_most_significant() {
+            let v = vec![0x12, 0x34, 0x56, 0x78];
+            // Most significant bytes for a reversed display are the last-in-memory ones.
+            assert_eq!(format!("{0:.4}", v.as_hex_reversed()), "7856");
+        }
+
+        #[test]
+        fn reversed_precision_with_padding() {
+            let v = vec![0x12, 0x34, 0x56, 0x78];
+            assert_eq!(format!("{0:10.4}", v.as_hex_reversed()), "7856      ");
+        }
+
+        #[test]
+        fn reversed_precision_with_padding_when_precision_exceeds_width_minus_content() {
+            let v = vec![0x12, 0x34, 0x56, 0x78];
+            assert_eq!(format!("{0:12.10}", v.as_hex_reversed()), "78563412    ");
+        }
+
+        #[test]
+        fn reversed_odd_precision_one_nibble_short_of_full_length() {
+            // Precision `2 * len - 1` is the boundary the old `bytes.len() > (max + 1) / 2` guard
+            // got wrong: for `len = 4` that's `4 > 4`, so it used to skip truncation entirely.
+            let v = vec![0x12, 0x34, 0x56, 0x78];
+            assert_eq!(format!("{0:.7}", v.as_hex_reversed()), "7856341");
+        }
+
+        #[test]
+        fn reversed_odd_precision_one_nibble_short_of_full_length_single_byte() {
+            let v = vec![0xab];
+            assert_eq!(format!("{0:.1}", v.as_hex_reversed()), "a");
+        }
+
+        #[test]
+        fn iter_display_hex_matches_slice() {
+            let v = vec![0x12, 0x34, 0x56, 0x78];
+            assert_eq!(format!("{}", v.iter().copied().as_hex()), format!("{}", v.as_hex()));
+        }
+
+        #[test]
+        fn iter_display_hex_supports_mapped_streams() {
+            let v = vec![(0xde, ()), (0xad, ())];
+            assert_eq!(format!("{}", v.into_iter().map(|(b, _)| b).as_hex()), "dead");
+        }
+
+        #[test]
+        fn iter_display_hex_long() {
+            let v: Vec<u8> = (0u8..=255).cycle().take(600).collect();
+            assert_eq!(format!("{}", v.iter().copied().as_hex()), format!("{}", v.as_hex()));
+        }
+
+        #[test]
+        fn iter_display_hex_precision_and_padding() {
+            let v = vec![0x12, 0x34, 0x56, 0x78];
+            assert_eq!(format!("{0:10.4}", v.iter().copied().as_hex()), "1234      ");
+        }
+
+        #[test]
+        fn iter_display_hex_pads_right_when_precision_exceeds_width_minus_content() {
+            let v = vec![0x12, 0x34, 0x56, 0x78];
+            assert_eq!(format!("{0:12.10}", v.iter().copied().as_hex()), "12345678    ");
+        }
+    }
+
+    #[cfg(all(not(feature = "std"), feature = "alloc"))]
+    mod hex_writer_no_std {
+        use super::super::*;
+        use crate::alloc::string::String;
+        use core::fmt::Write;
+
+        #[test]
+        fn hex_writer_write_bytes_matches_as_hex_string() {
+            let bytes = [0xde, 0xad, 0xbe, 0xef];
+            let mut out = String::new();
+            let mut w = HexWriter::new(&mut out, Case::Lower);
+            w.write_bytes(&bytes).unwrap();
+            assert_eq!(w.finish(), "deadbeef");
+        }
+
+        #[test]
+        fn hex_writer_impl_fmt_write() {
+            // `HexWriter` implements `fmt::Write` itself, hex-encoding whatever text is written
+            // through it, so it can be handed to anything generic over `fmt::Write`.
+            let mut out = String::new();
+            let mut w = HexWriter::new(&mut out, Case::Upper);
+            write!(w, "{}", "\x1f\x2a").unwrap();
+            assert_eq!(w.finish(), "1F2A");
+        }
+    }
+
+    #[cfg(feature = "std")]
+    mod hex_writer {
+        use super::super::*;
+        use std::io::Write;
+
+        #[test]
+        fn hex_writer_matches_as_hex_string() {
+            let bytes = [0xde, 0xad, 0xbe, 0xef];
+            let mut out = Vec::new();
+            let mut w = HexWriter::new(&mut out, Case::Lower);
+            w.write_all(&bytes).unwrap();
+            w.finish().unwrap();
+            assert_eq!(std::str::from_utf8(&out).unwrap(), "deadbeef");
+        }
+
+        #[test]
+        fn hex_writer_handles_multiple_writes_across_buffer_boundary() {
+            let bytes = [0xab; 600];
+            let mut out = Vec::new();
+            let mut w = HexWriter::new(&mut out, Case::Upper);
+            w.write_all(&bytes[..300]).unwrap();
+            w.write_all(&bytes[300..]).unwrap();
+            w.finish().unwrap();
+            assert_eq!(std::str::from_utf8(&out).unwrap(), "AB".repeat(600));
+        }
     }
 }